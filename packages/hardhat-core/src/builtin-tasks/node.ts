@@ -174,16 +174,20 @@ subtask(TASK_NODE_CREATE_SERVER)
   .addParam("port", undefined, undefined, types.int)
   .addParam("provider", undefined, undefined, types.any)
   .setAction(
-    async ({
-      hostname,
-      port,
-      provider,
-    }: {
-      hostname: string;
-      port: number;
-      provider: EthereumProvider;
-    }): Promise<JsonRpcServer> => {
+    async (
+      {
+        hostname,
+        port,
+        provider,
+      }: {
+        hostname: string;
+        port: number;
+        provider: EthereumProvider;
+      },
+      { config }
+    ): Promise<JsonRpcServer> => {
       const serverConfig: JsonRpcServerConfig = {
+        ...config.networks[HARDHAT_NETWORK_NAME].server,
         hostname,
         port,
         provider,
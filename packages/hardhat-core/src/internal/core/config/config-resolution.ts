@@ -14,6 +14,8 @@ import {
   HardhatNetworkMiningUserConfig,
   HardhatNetworkMempoolConfig,
   HardhatNetworkMempoolUserConfig,
+  HardhatNetworkServerConfig,
+  HardhatNetworkServerUserConfig,
   HardhatNetworkUserConfig,
   HardhatUserConfig,
   HDAccountsUserConfig,
@@ -168,9 +170,16 @@ function resolveHardhatNetworkConfig(
     if (httpHeaders !== undefined) {
       forking.httpHeaders = httpHeaders;
     }
+
+    const proxyUnsupportedMethods =
+      hardhatNetworkConfig.forking?.proxyUnsupportedMethods;
+    if (proxyUnsupportedMethods !== undefined) {
+      forking.proxyUnsupportedMethods = proxyUnsupportedMethods;
+    }
   }
 
   const mining = resolveMiningConfig(hardhatNetworkConfig.mining);
+  const server = resolveServerConfig(hardhatNetworkConfig.server);
 
   const minGasPrice = BigInt(
     hardhatNetworkConfig.minGasPrice ??
@@ -228,12 +237,16 @@ function resolveHardhatNetworkConfig(
     initialDate,
     minGasPrice,
     chains,
+    server,
   };
 
   // We do it this way because ts gets lost otherwise
   if (config.forking === undefined) {
     delete config.forking;
   }
+  if (config.server === undefined) {
+    delete config.server;
+  }
   if (config.initialBaseFeePerGas === undefined) {
     delete config.initialBaseFeePerGas;
   }
@@ -359,6 +372,25 @@ function resolveMempoolConfig(
   } as HardhatNetworkMempoolConfig;
 }
 
+function resolveServerConfig(
+  userConfig: HardhatNetworkServerUserConfig | undefined
+): HardhatNetworkServerConfig | undefined {
+  if (userConfig === undefined) {
+    return undefined;
+  }
+
+  const config: HardhatNetworkServerConfig = { ...userConfig };
+
+  // Node reports the methods of incoming requests in upper case
+  if (userConfig.allowedHttpMethods !== undefined) {
+    config.allowedHttpMethods = userConfig.allowedHttpMethods.map((method) =>
+      method.toUpperCase()
+    );
+  }
+
+  return config;
+}
+
 function resolveSolidityConfig(userConfig: HardhatUserConfig): SolidityConfig {
   const userSolidityConfig = userConfig.solidity ?? DEFAULT_SOLC_VERSION;
 
@@ -68,6 +68,88 @@ function getPrivateKeyError(index: number, network: string, message: string) {
   return `Invalid account: #${index} for network: ${network} - ${message}`;
 }
 
+function validatePositiveNumber(
+  value: unknown,
+  path: string,
+  errors: string[]
+) {
+  if (typeof value === "number" && !(value > 0)) {
+    errors.push(getErrorMessage(path, value, "positive number"));
+  }
+}
+
+function validateHardhatNetworkServerConfig(server: any, errors: string[]) {
+  const path = `HardhatConfig.networks.${HARDHAT_NETWORK_NAME}.server`;
+
+  validatePositiveNumber(
+    server.maxRequestBodySize,
+    `${path}.maxRequestBodySize`,
+    errors
+  );
+  validatePositiveNumber(
+    server.requestTimeout,
+    `${path}.requestTimeout`,
+    errors
+  );
+
+  if (
+    typeof server.faultInjection === "object" &&
+    server.faultInjection !== null
+  ) {
+    errors.push(...getFaultInjectionValidationErrors(server.faultInjection));
+  }
+}
+
+/**
+ * Returns the errors of the range checks of a fault injection config, which
+ * io-ts can't validate. It's also used by the JSON-RPC server, which can be
+ * configured without going through the Hardhat config.
+ */
+export function getFaultInjectionValidationErrors(
+  faultInjection: any
+): string[] {
+  const path = `HardhatConfig.networks.${HARDHAT_NETWORK_NAME}.server.faultInjection`;
+  const errors: string[] = [];
+
+  validateFaultsConfig(faultInjection, path, errors);
+
+  if (
+    typeof faultInjection.methods === "object" &&
+    faultInjection.methods !== null
+  ) {
+    for (const [method, methodConfig] of Object.entries<any>(
+      faultInjection.methods
+    )) {
+      if (typeof methodConfig === "object" && methodConfig !== null) {
+        validateFaultsConfig(
+          methodConfig,
+          `${path}.methods.${method}`,
+          errors
+        );
+      }
+    }
+  }
+
+  return errors;
+}
+
+function validateFaultsConfig(faults: any, path: string, errors: string[]) {
+  if (typeof faults.latency === "number" && !(faults.latency >= 0)) {
+    errors.push(
+      getErrorMessage(`${path}.latency`, faults.latency, "non-negative number")
+    );
+  }
+
+  for (const rateName of ["errorRate", "malformedResponseRate"]) {
+    const rate = faults[rateName];
+    if (typeof rate === "number" && !(rate >= 0 && rate <= 1)) {
+      errors.push(
+        getErrorMessage(`${path}.${rateName}`, rate, "number between 0 and 1")
+      );
+    }
+  }
+}
+
 function validatePrivateKey(
   privateKey: unknown,
   index: number,
@@ -222,6 +304,7 @@ const HardhatNetworkForkingConfig = t.type({
   enabled: optional(t.boolean),
   url: t.string,
   blockNumber: optional(t.number),
+  proxyUnsupportedMethods: optional(t.boolean),
 });
 
 const HardhatNetworkMempoolConfig = t.type({
@@ -270,6 +353,31 @@ const HardhatNetworkChainConfig = t.type({
 
 const HardhatNetworkChainsConfig = t.record(Integer, HardhatNetworkChainConfig);
 
+const hardhatNetworkFaultsFields = {
+  latency: optional(t.number),
+  errorRate: optional(t.number),
+  malformedResponseRate: optional(t.number),
+};
+
+const HardhatNetworkFaultInjectionConfig = t.type({
+  ...hardhatNetworkFaultsFields,
+  seed: optional(t.string),
+  methods: optional(t.record(t.string, t.type(hardhatNetworkFaultsFields))),
+});
+
+const HardhatNetworkServerConfig = t.type({
+  allowedOrigins: optional(t.array(t.string)),
+  allowedHttpMethods: optional(t.array(t.string)),
+  maxRequestBodySize: optional(t.number),
+  // Node requires an integer timeout. The Integer type above only decodes
+  // record keys, so io-ts' refinement is used instead.
+  requestTimeout: optional(t.Integer),
+  allowedMethods: optional(t.array(t.string)),
+  disabledMethods: optional(t.array(t.string)),
+  enableMetrics: optional(t.boolean),
+  faultInjection: optional(HardhatNetworkFaultInjectionConfig),
+});
+
 const commonNetworkConfigFields = {
   chainId: optional(t.number),
   from: optional(t.string),
@@ -299,6 +407,7 @@ const HardhatNetworkConfig = t.type({
   mining: optional(HardhatNetworkMiningConfig),
   coinbase: optional(address),
   chains: optional(HardhatNetworkChainsConfig),
+  server: optional(HardhatNetworkServerConfig),
 });
 
 const HDAccountsConfig = t.type({
@@ -362,8 +471,14 @@ const HardhatConfig = t.type(
  * @param config
  */
 export function validateConfig(config: any) {
-  const errors = getValidationErrors(config);
+  throwIfInvalid(getValidationErrors(config));
+}
 
+/**
+ * Throws a HardhatError listing the given config errors, if there are any.
+ * @param errors
+ */
+export function throwIfInvalid(errors: string[]) {
   if (errors.length === 0) {
     return;
   }
@@ -517,6 +632,13 @@ export function getValidationErrors(config: any): string[] {
           );
         }
       }
+
+      if (
+        typeof hardhatNetwork.server === "object" &&
+        hardhatNetwork.server !== null
+      ) {
+        validateHardhatNetworkServerConfig(hardhatNetwork.server, errors);
+      }
     }
 
     for (const [networkName, netConfig] of Object.entries<any>(
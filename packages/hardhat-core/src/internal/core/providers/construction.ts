@@ -110,6 +110,8 @@ export async function createProvider(
             : undefined,
         experimentalHardhatNetworkMessageTraceHooks,
         forkConfig,
+        proxyUnsupportedMethods:
+          hardhatNetConfig.forking?.proxyUnsupportedMethods ?? false,
         forkCachePath:
           paths !== undefined ? getForkCacheDirPath(paths) : undefined,
         enableTransientStorage:
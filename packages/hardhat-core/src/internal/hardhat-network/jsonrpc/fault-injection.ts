@@ -0,0 +1,68 @@
+import {
+  getFaultInjectionValidationErrors,
+  throwIfInvalid,
+} from "../../core/config/config-validation";
+import { RandomBufferGenerator } from "../provider/utils/random";
+
+export interface JsonRpcFaultsConfig {
+  // Milliseconds to wait before handling each request.
+  latency?: number;
+
+  // Probability, between 0 and 1, of failing a request without handling it.
+  errorRate?: number;
+
+  // Probability, between 0 and 1, of returning a response that has neither a
+  // result nor an error.
+  malformedResponseRate?: number;
+}
+
+export interface JsonRpcFaultInjectionConfig extends JsonRpcFaultsConfig {
+  // Seed used to decide which requests fail, so that runs are reproducible.
+  seed?: string;
+
+  // Per-method settings, which override the global ones.
+  methods?: { [method: string]: JsonRpcFaultsConfig };
+}
+
+export interface JsonRpcFaults {
+  latency: number;
+  error: boolean;
+  malformedResponse: boolean;
+}
+
+/**
+ * Decides which artificial faults are applied to each JSON-RPC request.
+ */
+export class JsonRpcFaultInjector {
+  private readonly _generator: RandomBufferGenerator;
+
+  constructor(private readonly _config: JsonRpcFaultInjectionConfig) {
+    throwIfInvalid(getFaultInjectionValidationErrors(_config));
+
+    this._generator = RandomBufferGenerator.create(_config.seed ?? "seed");
+  }
+
+  public getFaults(method: string): JsonRpcFaults {
+    const methodConfig = this._config.methods?.[method] ?? {};
+
+    const latency = methodConfig.latency ?? this._config.latency ?? 0;
+    const errorRate = methodConfig.errorRate ?? this._config.errorRate ?? 0;
+    const malformedResponseRate =
+      methodConfig.malformedResponseRate ??
+      this._config.malformedResponseRate ??
+      0;
+
+    return {
+      latency,
+      error: this._nextRandom() < errorRate,
+      malformedResponse: this._nextRandom() < malformedResponseRate,
+    };
+  }
+
+  /** Returns a number in the [0, 1) range. */
+  private _nextRandom(): number {
+    const bytes = this._generator.next();
+
+    return Buffer.from(bytes).readUInt32BE(0) / 2 ** 32;
+  }
+}
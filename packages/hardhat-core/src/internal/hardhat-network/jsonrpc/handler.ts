@@ -3,10 +3,13 @@ import getRawBody from "raw-body";
 import WebSocket from "ws";
 
 import { EIP1193Provider } from "../../../types";
+import { rpcQuantityToNumber } from "../../core/jsonrpc/types/base-types";
 import {
   InternalError,
   InvalidJsonInputError,
   InvalidRequestError,
+  MethodNotFoundError,
+  MethodNotSupportedError,
   ProviderError,
 } from "../../core/providers/errors";
 import {
@@ -18,22 +21,120 @@ import {
   JsonRpcResponse,
 } from "../../util/jsonrpc";
 
+import {
+  JsonRpcFaultInjectionConfig,
+  JsonRpcFaultInjector,
+} from "./fault-injection";
+import { JsonRpcChainMetrics, JsonRpcMetrics } from "./metrics";
+
 /* eslint-disable @nomicfoundation/hardhat-internal-rules/only-hardhat-error */
 
+export interface JsonRpcHandlerConfig {
+  // Origins that are allowed to access the server. Any origin is allowed if
+  // undefined. Requests without an Origin header are always allowed.
+  allowedOrigins?: string[];
+
+  // HTTP methods accepted by the server, besides OPTIONS. Any method is
+  // accepted if undefined. Methods are compared case-insensitively.
+  allowedHttpMethods?: string[];
+
+  // Maximum size in bytes of an HTTP request body or a websocket message.
+  maxRequestBodySize?: number;
+
+  // JSON-RPC methods that can be called. A trailing "*" matches any method
+  // with that prefix, like "eth_*". Every method is allowed if undefined.
+  allowedMethods?: string[];
+
+  // JSON-RPC methods that can't be called, using the same patterns as
+  // allowedMethods. Takes precedence over allowedMethods.
+  disabledMethods?: string[];
+
+  // Whether to collect request metrics and serve them in the Prometheus
+  // format on GET /metrics, together with the block number and the number of
+  // pending transactions. Requests for methods that the provider doesn't
+  // know are reported with the "unknown" method label.
+  enableMetrics?: boolean;
+
+  // Artificial latency, errors and malformed responses injected into the
+  // handled requests, to test how clients deal with them. eth_subscribe and
+  // eth_unsubscribe are never faulted.
+  faultInjection?: JsonRpcFaultInjectionConfig;
+}
+
 export class JsonRpcHandler {
-  constructor(private readonly _provider: EIP1193Provider) {}
+  private readonly _metrics?: JsonRpcMetrics;
+  private readonly _faultInjector?: JsonRpcFaultInjector;
+
+  constructor(
+    private readonly _provider: EIP1193Provider,
+    private readonly _config: JsonRpcHandlerConfig = {}
+  ) {
+    if (_config.enableMetrics === true) {
+      this._metrics = new JsonRpcMetrics();
+    }
+
+    if (_config.faultInjection !== undefined) {
+      this._faultInjector = new JsonRpcFaultInjector(_config.faultInjection);
+    }
+  }
+
+  public isOriginAllowed = (origin: string | undefined): boolean => {
+    const { allowedOrigins } = this._config;
+
+    return (
+      allowedOrigins === undefined ||
+      origin === undefined ||
+      allowedOrigins.includes("*") ||
+      allowedOrigins.includes(origin)
+    );
+  };
 
   public handleHttp = async (req: IncomingMessage, res: ServerResponse) => {
-    this._setCorsHeaders(res);
+    if (!this.isOriginAllowed(req.headers.origin)) {
+      this._sendEmptyResponse(res, 403);
+      return;
+    }
+
+    this._setCorsHeaders(req, res);
     if (req.method === "OPTIONS") {
       this._sendEmptyResponse(res);
       return;
     }
 
+    if (!this._isHttpMethodAllowed(req.method)) {
+      this._sendEmptyResponse(res, 405);
+      return;
+    }
+
+    if (
+      this._metrics !== undefined &&
+      req.method === "GET" &&
+      req.url === "/metrics"
+    ) {
+      await this._sendMetricsResponse(res, this._metrics);
+      return;
+    }
+
     let jsonHttpRequest: any;
     try {
-      jsonHttpRequest = await _readJsonHttpRequest(req);
+      jsonHttpRequest = await _readJsonHttpRequest(
+        req,
+        this._config.maxRequestBodySize
+      );
     } catch (error) {
+      if (_isEntityTooLargeError(error)) {
+        this._sendResponse(
+          res,
+          _handleError(
+            new InvalidRequestError(
+              `Request body exceeds the maximum size of ${this._config.maxRequestBodySize} bytes`
+            )
+          ),
+          413
+        );
+        return;
+      }
+
       this._sendResponse(res, _handleError(error));
       return;
     }
@@ -117,23 +218,90 @@ export class JsonRpcHandler {
     });
   };
 
-  private _sendEmptyResponse(res: ServerResponse) {
-    res.writeHead(200);
+  private _isHttpMethodAllowed(method: string | undefined): boolean {
+    const { allowedHttpMethods } = this._config;
+
+    return (
+      allowedHttpMethods === undefined ||
+      (method !== undefined &&
+        allowedHttpMethods.some(
+          (allowedMethod) => allowedMethod.toUpperCase() === method
+        ))
+    );
+  }
+
+  private _isJsonRpcMethodAllowed(method: string): boolean {
+    const { allowedMethods, disabledMethods } = this._config;
+
+    if (
+      disabledMethods !== undefined &&
+      _matchesSomeMethodPattern(method, disabledMethods)
+    ) {
+      return false;
+    }
+
+    return (
+      allowedMethods === undefined ||
+      _matchesSomeMethodPattern(method, allowedMethods)
+    );
+  }
+
+  private _sendEmptyResponse(res: ServerResponse, statusCode = 200) {
+    res.writeHead(statusCode);
     res.end();
   }
 
-  private _setCorsHeaders(res: ServerResponse) {
-    res.setHeader("Access-Control-Allow-Origin", "*");
+  private _setCorsHeaders(req: IncomingMessage, res: ServerResponse) {
+    if (this._config.allowedOrigins === undefined) {
+      res.setHeader("Access-Control-Allow-Origin", "*");
+    } else if (req.headers.origin !== undefined) {
+      res.setHeader("Access-Control-Allow-Origin", req.headers.origin);
+      res.setHeader("Vary", "Origin");
+    }
+
     res.setHeader("Access-Control-Request-Method", "*");
-    res.setHeader("Access-Control-Allow-Methods", "OPTIONS, GET");
+    res.setHeader(
+      "Access-Control-Allow-Methods",
+      this._config.allowedHttpMethods !== undefined
+        ? ["OPTIONS", ...this._config.allowedHttpMethods].join(", ")
+        : "OPTIONS, GET"
+    );
     res.setHeader("Access-Control-Allow-Headers", "*");
   }
 
-  private _sendResponse(
+  private async _sendMetricsResponse(
     res: ServerResponse,
-    rpcResp: JsonRpcResponse | JsonRpcResponse[]
+    metrics: JsonRpcMetrics
   ) {
+    let chainMetrics: JsonRpcChainMetrics | undefined;
+    try {
+      const [blockNumber, pendingTransactions] = await Promise.all([
+        this._provider.request({ method: "eth_blockNumber" }),
+        this._provider.request({
+          method: "eth_getBlockTransactionCountByNumber",
+          params: ["pending"],
+        }),
+      ]);
+
+      chainMetrics = {
+        blockNumber: rpcQuantityToNumber(blockNumber as string),
+        pendingTransactions: rpcQuantityToNumber(pendingTransactions as string),
+      };
+    } catch {
+      // the request metrics are still served if the chain can't be queried
+    }
+
     res.statusCode = 200;
+    res.setHeader("Content-Type", "text/plain; version=0.0.4");
+    res.end(metrics.render(chainMetrics));
+  }
+
+  private _sendResponse(
+    res: ServerResponse,
+    rpcResp: JsonRpcResponse | JsonRpcResponse[],
+    statusCode = 200
+  ) {
+    res.statusCode = statusCode;
     res.setHeader("Content-Type", "application/json");
     res.end(JSON.stringify(rpcResp));
   }
@@ -148,12 +316,49 @@ export class JsonRpcHandler {
     const rpcReq: JsonRpcRequest = req;
     let rpcResp: JsonRpcResponse | undefined;
 
+    if (!this._isJsonRpcMethodAllowed(rpcReq.method)) {
+      rpcResp = _handleError(
+        new MethodNotFoundError(
+          `The method ${rpcReq.method} does not exist/is not available`
+        )
+      );
+      rpcResp.id = rpcReq.id !== undefined ? rpcReq.id : null;
+      return rpcResp;
+    }
+
+    // Subscriptions are tracked from the responses of these methods, so
+    // they are never faulted
+    const faults = _isSubscriptionMethod(rpcReq.method)
+      ? undefined
+      : this._faultInjector?.getFaults(rpcReq.method);
+    if (faults !== undefined) {
+      if (faults.latency > 0) {
+        await new Promise((resolve) => setTimeout(resolve, faults.latency));
+      }
+
+      if (faults.error) {
+        rpcResp = _handleError(new InternalError("Injected transient error"));
+        rpcResp.id = rpcReq.id !== undefined ? rpcReq.id : null;
+        return rpcResp;
+      }
+    }
+
+    const startTime = process.hrtime.bigint();
     try {
       rpcResp = await this._handleRequest(rpcReq);
     } catch (error) {
       rpcResp = _handleError(error);
     }
 
+    if (this._metrics !== undefined) {
+      const elapsedNanoseconds = process.hrtime.bigint() - startTime;
+      this._metrics.observeRequest(
+        _isUnknownMethodResponse(rpcResp) ? "unknown" : rpcReq.method,
+        Number(elapsedNanoseconds) / 1e9,
+        !isSuccessfulJsonResponse(rpcResp)
+      );
+    }
+
     // Validate the RPC response.
     if (!isValidJsonResponse(rpcResp)) {
       // Malformed response coming from the provider, report to user as an internal error.
@@ -164,6 +369,11 @@ export class JsonRpcHandler {
       rpcResp.id = rpcReq.id !== undefined ? rpcReq.id : null;
     }
 
+    if (faults?.malformedResponse === true) {
+      // a response without a result or an error is invalid
+      return { jsonrpc: "2.0", id: rpcResp.id } as JsonRpcResponse;
+    }
+
     return rpcResp;
   }
 
@@ -201,15 +411,23 @@ export class JsonRpcHandler {
   };
 }
 
-const _readJsonHttpRequest = async (req: IncomingMessage): Promise<any> => {
+const _readJsonHttpRequest = async (
+  req: IncomingMessage,
+  limit?: number
+): Promise<any> => {
   let json;
 
   try {
-    const buf = await getRawBody(req);
+    const buf = await getRawBody(req, { limit });
     const text = buf.toString();
 
     json = JSON.parse(text);
   } catch (error) {
+    if (_isEntityTooLargeError(error)) {
+      // eslint-disable-next-line @nomicfoundation/hardhat-internal-rules/only-hardhat-error
+      throw error;
+    }
+
     if (error instanceof Error) {
       throw new InvalidJsonInputError(`Parse error: ${error.message}`);
     }
@@ -221,6 +439,30 @@ const _readJsonHttpRequest = async (req: IncomingMessage): Promise<any> => {
   return json;
 };
 
+const _matchesSomeMethodPattern = (
+  method: string,
+  patterns: string[]
+): boolean =>
+  patterns.some((pattern) =>
+    pattern.endsWith("*")
+      ? method.startsWith(pattern.slice(0, -1))
+      : method === pattern
+  );
+
+const _isSubscriptionMethod = (method: string): boolean =>
+  method === "eth_subscribe" || method === "eth_unsubscribe";
+
+// Method names are client input, so they can only be used as metric labels
+// once the provider recognized them
+const _isUnknownMethodResponse = (rpcResp: JsonRpcResponse): boolean =>
+  !isSuccessfulJsonResponse(rpcResp) &&
+  (rpcResp.error.code === MethodNotFoundError.CODE ||
+    rpcResp.error.code === MethodNotSupportedError.CODE);
+
+// raw-body signals that the request exceeded the limit with this error type
+const _isEntityTooLargeError = (error: any): boolean =>
+  error?.type === "entity.too.large";
+
 const _readWsRequest = (msg: string): JsonRpcRequest | JsonRpcRequest[] => {
   let json: any;
   try {
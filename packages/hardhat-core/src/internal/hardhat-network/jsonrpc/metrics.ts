@@ -0,0 +1,93 @@
+interface MethodMetrics {
+  requests: number;
+  errors: number;
+  durationSeconds: number;
+}
+
+export interface JsonRpcChainMetrics {
+  blockNumber: number;
+  pendingTransactions: number;
+}
+
+/**
+ * Collects per-method request metrics of the JSON-RPC server and renders
+ * them in the Prometheus text exposition format.
+ */
+export class JsonRpcMetrics {
+  private readonly _methods = new Map<string, MethodMetrics>();
+
+  public observeRequest(
+    method: string,
+    durationSeconds: number,
+    failed: boolean
+  ) {
+    let metrics = this._methods.get(method);
+    if (metrics === undefined) {
+      metrics = { requests: 0, errors: 0, durationSeconds: 0 };
+      this._methods.set(method, metrics);
+    }
+
+    metrics.requests += 1;
+    metrics.durationSeconds += durationSeconds;
+    if (failed) {
+      metrics.errors += 1;
+    }
+  }
+
+  public render(chainMetrics?: JsonRpcChainMetrics): string {
+    const lines = [
+      "# HELP hardhat_jsonrpc_requests_total Number of JSON-RPC requests handled.",
+      "# TYPE hardhat_jsonrpc_requests_total counter",
+      ...this._renderSamples(
+        "hardhat_jsonrpc_requests_total",
+        (metrics) => metrics.requests
+      ),
+      "# HELP hardhat_jsonrpc_request_errors_total Number of JSON-RPC requests that returned an error.",
+      "# TYPE hardhat_jsonrpc_request_errors_total counter",
+      ...this._renderSamples(
+        "hardhat_jsonrpc_request_errors_total",
+        (metrics) => metrics.errors
+      ),
+      "# HELP hardhat_jsonrpc_request_duration_seconds Time spent handling JSON-RPC requests.",
+      "# TYPE hardhat_jsonrpc_request_duration_seconds summary",
+      ...this._renderSamples(
+        "hardhat_jsonrpc_request_duration_seconds_sum",
+        (metrics) => metrics.durationSeconds
+      ),
+      ...this._renderSamples(
+        "hardhat_jsonrpc_request_duration_seconds_count",
+        (metrics) => metrics.requests
+      ),
+    ];
+
+    if (chainMetrics !== undefined) {
+      lines.push(
+        "# HELP hardhat_block_number Number of the latest block.",
+        "# TYPE hardhat_block_number gauge",
+        `hardhat_block_number ${chainMetrics.blockNumber}`,
+        "# HELP hardhat_pending_transactions Number of transactions in the mempool that would be included in the next block.",
+        "# TYPE hardhat_pending_transactions gauge",
+        `hardhat_pending_transactions ${chainMetrics.pendingTransactions}`
+      );
+    }
+
+    return `${lines.join("\n")}\n`;
+  }
+
+  private _renderSamples(
+    name: string,
+    getValue: (metrics: MethodMetrics) => number
+  ): string[] {
+    return [...this._methods.entries()].map(
+      ([method, metrics]) =>
+        `${name}{method="${_escapeLabelValue(method)}"} ${getValue(metrics)}`
+    );
+  }
+}
+
+function _escapeLabelValue(value: string): string {
+  return value
+    .replace(/\\/g, "\\\\")
+    .replace(/"/g, '\\"')
+    .replace(/\n/g, "\\n");
+}
@@ -11,15 +11,19 @@ import {
 } from "../../../types";
 import { HttpProvider } from "../../core/providers/http";
 
-import { JsonRpcHandler } from "./handler";
+import { JsonRpcHandler, JsonRpcHandlerConfig } from "./handler";
 
 const log = debug("hardhat:core:hardhat-network:jsonrpc");
 
-export interface JsonRpcServerConfig {
+export interface JsonRpcServerConfig extends JsonRpcHandlerConfig {
   hostname: string;
   port: number;
 
   provider: EIP1193Provider;
+
+  // Maximum time in milliseconds to receive an entire HTTP request. Node's
+  // default is used if undefined.
+  requestTimeout?: number;
 }
 
 export class JsonRpcServer implements IJsonRpcServer {
@@ -32,12 +36,38 @@ export class JsonRpcServer implements IJsonRpcServer {
 
     this._config = config;
 
-    const handler = new JsonRpcHandler(config.provider);
+    const handler = new JsonRpcHandler(config.provider, {
+      allowedOrigins: config.allowedOrigins,
+      allowedHttpMethods: config.allowedHttpMethods,
+      maxRequestBodySize: config.maxRequestBodySize,
+      allowedMethods: config.allowedMethods,
+      disabledMethods: config.disabledMethods,
+      enableMetrics: config.enableMetrics,
+      faultInjection: config.faultInjection,
+    });
 
-    this._httpServer = http.createServer();
-    this._wsServer = new WSServer({
+    this._httpServer =
+      config.requestTimeout !== undefined
+        ? http.createServer({
+            requestTimeout: config.requestTimeout,
+            // Node only checks for timed out requests once per interval, which
+            // is 30 seconds by default
+            connectionsCheckingInterval: Math.min(
+              config.requestTimeout,
+              30000
+            ),
+          })
+        : http.createServer();
+
+    const wsServerOptions: WsT.ServerOptions = {
       server: this._httpServer,
-    });
+      verifyClient: ({ origin }) => handler.isOriginAllowed(origin),
+    };
+    if (config.maxRequestBodySize !== undefined) {
+      wsServerOptions.maxPayload = config.maxRequestBodySize;
+    }
+
+    this._wsServer = new WSServer(wsServerOptions);
 
     this._httpServer.on("request", handler.handleHttp);
     this._wsServer.on("connection", handler.handleWs);
@@ -29,6 +29,7 @@ import {
   HARDHAT_NETWORK_RESET_EVENT,
   HARDHAT_NETWORK_REVERT_SNAPSHOT_EVENT,
 } from "../../constants";
+import { optionalRpcHardhatNetworkConfig } from "../../core/jsonrpc/types/input/hardhat-network";
 import {
   rpcCompilerInput,
   rpcCompilerOutput,
@@ -37,10 +38,13 @@ import { validateParams } from "../../core/jsonrpc/types/input/validation";
 import {
   InvalidArgumentsError,
   InvalidInputError,
+  MethodNotFoundError,
+  MethodNotSupportedError,
   ProviderError,
 } from "../../core/providers/errors";
-import { isErrorResponse } from "../../core/providers/http";
+import { HttpProvider, isErrorResponse } from "../../core/providers/http";
 import { getHardforkName } from "../../util/hardforks";
+import { FailedJsonRpcResponse } from "../../util/jsonrpc";
 import { createModelsAndDecodeBytecodes } from "../stack-traces/compiler-to-model";
 import { ConsoleLogger } from "../stack-traces/consoleLogger";
 import { ContractsIdentifier } from "../stack-traces/contracts-identifier";
@@ -119,6 +123,7 @@ interface HardhatNetworkProviderConfig {
   experimentalHardhatNetworkMessageTraceHooks?: BoundExperimentalHardhatNetworkMessageTraceHook[];
   forkConfig?: ForkConfig;
   forkCachePath?: string;
+  proxyUnsupportedMethods?: boolean;
   enableTransientStorage: boolean;
   enableRip7212: boolean;
 }
@@ -171,6 +176,11 @@ export class EdrProviderWrapper
   /** Used for internal stack trace tests. */
   private _vmTracer?: VMTracer;
 
+  private _proxyUnsupportedMethods = false;
+
+  /** The forked node, to which unsupported methods are proxied. */
+  private _forkProxyProvider?: EIP1193Provider;
+
   private constructor(
     private readonly _provider: EdrProviderT,
     // we add this for backwards-compatibility with plugins like solidity-coverage
@@ -324,14 +334,19 @@ export class EdrProviderWrapper
       wrapper._ethEventListener.bind(wrapper)
     );
 
+    wrapper._proxyUnsupportedMethods = config.proxyUnsupportedMethods ?? false;
+    wrapper._setForkProxyProvider(config.forkConfig);
+
     return wrapper;
   }
 
   public async request(args: RequestArguments): Promise<unknown> {
     if (args.params !== undefined && !Array.isArray(args.params)) {
-      throw new InvalidInputError(
-        "Hardhat Network doesn't support JSON-RPC params sent as an object"
-      );
+      if (this._forkProxyProvider !== undefined) {
+        return this._requestWithObjectParams(args.method, args.params);
+      }
+
+      throw new InvalidInputError(OBJECT_PARAMS_ERROR_MESSAGE);
     }
 
     const params = args.params ?? [];
@@ -421,6 +436,17 @@ export class EdrProviderWrapper
     }
 
     if (isErrorResponse(response)) {
+      if (
+        this._forkProxyProvider !== undefined &&
+        isUnsupportedMethodError(response)
+      ) {
+        log(`Proxying unsupported method ${args.method} to the forked node`);
+        return this._forkProxyProvider.request({
+          method: args.method,
+          params,
+        });
+      }
+
       let error;
 
       const solidityTrace = responseObject.solidityTrace;
@@ -453,6 +479,12 @@ export class EdrProviderWrapper
 
     if (args.method === "hardhat_reset") {
       this.emit(HARDHAT_NETWORK_RESET_EVENT);
+
+      const [resetConfig] = validateParams(
+        params,
+        optionalRpcHardhatNetworkConfig
+      );
+      this._setForkProxyProvider(resetConfig?.forking);
     } else if (args.method === "evm_revert") {
       this.emit(HARDHAT_NETWORK_REVERT_SNAPSHOT_EVENT);
     }
@@ -491,6 +523,55 @@ export class EdrProviderWrapper
     );
   }
 
+  /**
+   * EDR only accepts params sent as an array, so params sent as an object are
+   * only useful for methods that are forwarded to the forked node.
+   */
+  private async _requestWithObjectParams(
+    method: string,
+    params: object
+  ): Promise<unknown> {
+    const responseObject: Response = await this._provider.handleRequest(
+      JSON.stringify({ method, params })
+    );
+
+    const response =
+      typeof responseObject.data === "string"
+        ? JSON.parse(responseObject.data)
+        : responseObject.data;
+
+    if (!isErrorResponse(response)) {
+      return response.result;
+    }
+
+    if (
+      this._forkProxyProvider !== undefined &&
+      isUnsupportedMethodError(response)
+    ) {
+      log(`Proxying unsupported method ${method} to the forked node`);
+      return this._forkProxyProvider.request({ method, params });
+    }
+
+    throw new InvalidInputError(OBJECT_PARAMS_ERROR_MESSAGE);
+  }
+
+  private _setForkProxyProvider(
+    forkConfig:
+      | { jsonRpcUrl: string; httpHeaders?: { [name: string]: string } }
+      | undefined
+  ) {
+    if (!this._proxyUnsupportedMethods || forkConfig === undefined) {
+      this._forkProxyProvider = undefined;
+      return;
+    }
+
+    this._forkProxyProvider = new HttpProvider(
+      forkConfig.jsonRpcUrl,
+      "forked network",
+      forkConfig.httpHeaders
+    );
+  }
+
   private _setVerboseTracing(enabled: boolean) {
     this._provider.setVerboseTracing(enabled);
   }
@@ -616,6 +697,16 @@ export class EdrProviderWrapper
   }
 }
 
+const OBJECT_PARAMS_ERROR_MESSAGE =
+  "Hardhat Network doesn't support JSON-RPC params sent as an object";
+
+function isUnsupportedMethodError(response: FailedJsonRpcResponse): boolean {
+  return (
+    response.error.code === MethodNotFoundError.CODE ||
+    response.error.code === MethodNotSupportedError.CODE
+  );
+}
+
 async function clientVersion(edrClientVersion: string): Promise<string> {
   const hardhatPackage = await getPackageJson();
   const edrVersion = edrClientVersion.split("/")[1];
@@ -59,6 +59,7 @@ export interface HardhatNetworkUserConfig {
   chains?: HardhatNetworkChainsUserConfig;
   enableTransientStorage?: boolean;
   enableRip7212?: boolean;
+  server?: HardhatNetworkServerUserConfig;
 }
 
 export type HardhatNetworkAccountsUserConfig =
@@ -92,6 +93,30 @@ export interface HardhatNetworkForkingUserConfig {
   url: string;
   blockNumber?: number;
   httpHeaders?: { [name: string]: string };
+  proxyUnsupportedMethods?: boolean;
+}
+
+export interface HardhatNetworkServerUserConfig {
+  allowedOrigins?: string[];
+  allowedHttpMethods?: string[];
+  maxRequestBodySize?: number;
+  requestTimeout?: number;
+  allowedMethods?: string[];
+  disabledMethods?: string[];
+  enableMetrics?: boolean;
+  faultInjection?: HardhatNetworkFaultInjectionUserConfig;
+}
+
+export interface HardhatNetworkFaultsUserConfig {
+  latency?: number;
+  errorRate?: number;
+  malformedResponseRate?: number;
+}
+
+export interface HardhatNetworkFaultInjectionUserConfig
+  extends HardhatNetworkFaultsUserConfig {
+  seed?: string;
+  methods?: { [method: string]: HardhatNetworkFaultsUserConfig };
 }
 
 export type HttpNetworkAccountsUserConfig =
@@ -157,6 +182,7 @@ export interface HardhatNetworkConfig {
   allowBlocksWithSameTimestamp?: boolean;
   enableTransientStorage?: boolean;
   enableRip7212?: boolean;
+  server?: HardhatNetworkServerConfig;
 }
 
 export type HardhatNetworkAccountsConfig =
@@ -182,6 +208,30 @@ export interface HardhatNetworkForkingConfig {
   url: string;
   blockNumber?: number;
   httpHeaders?: { [name: string]: string };
+  proxyUnsupportedMethods?: boolean;
+}
+
+export interface HardhatNetworkServerConfig {
+  allowedOrigins?: string[];
+  allowedHttpMethods?: string[];
+  maxRequestBodySize?: number;
+  requestTimeout?: number;
+  allowedMethods?: string[];
+  disabledMethods?: string[];
+  enableMetrics?: boolean;
+  faultInjection?: HardhatNetworkFaultInjectionConfig;
+}
+
+export interface HardhatNetworkFaultsConfig {
+  latency?: number;
+  errorRate?: number;
+  malformedResponseRate?: number;
+}
+
+export interface HardhatNetworkFaultInjectionConfig
+  extends HardhatNetworkFaultsConfig {
+  seed?: string;
+  methods?: { [method: string]: HardhatNetworkFaultsConfig };
 }
 
 export interface HttpNetworkConfig {
@@ -1,3 +1,6 @@
+import { assert } from "chai";
+import { request } from "undici";
+
 import {
   TASK_NODE,
   TASK_NODE_SERVER_READY,
@@ -18,3 +21,85 @@ describe("node task", () => {
     // NB: If a file watcher persists past this test, then mocha will fail to exit cleanly.
   });
 });
+
+describe("node task with a server config", () => {
+  useFixtureProject("node-server-config");
+  useEnvironment();
+
+  it("should use the server config of the hardhat network", async function () {
+    const statusCodes: { [name: string]: number } = {};
+    const bodies: { [name: string]: string } = {};
+    let metrics = "";
+
+    this.env.tasks[TASK_NODE_SERVER_READY].setAction(
+      async ({ address, port, server }) => {
+        const sendRequest = async (
+          name: string,
+          options: {
+            method?: string;
+            httpMethod?: "POST" | "PUT";
+            origin?: string;
+            params?: unknown[];
+          } = {}
+        ) => {
+          const response = await request(`http://${address}:${port}/`, {
+            method: options.httpMethod ?? "POST",
+            headers: {
+              "Content-Type": "application/json",
+              Origin: options.origin ?? "http://localhost:3000",
+            },
+            body: JSON.stringify({
+              jsonrpc: "2.0",
+              id: 1,
+              method: options.method ?? "eth_chainId",
+              params: options.params ?? [],
+            }),
+          });
+
+          statusCodes[name] = response.statusCode;
+          bodies[name] = await response.body.text();
+        };
+
+        try {
+          await sendRequest("allowed");
+          await sendRequest("otherOrigin", { origin: "http://example.com" });
+          await sendRequest("otherHttpMethod", { httpMethod: "PUT" });
+          await sendRequest("tooLarge", { params: ["0x".padEnd(2000, "0")] });
+          await sendRequest("notAllowedMethod", { method: "hardhat_mine" });
+          await sendRequest("disabledMethod", { method: "evm_mine" });
+          await sendRequest("faultedMethod", { method: "eth_blockNumber" });
+
+          const metricsResponse = await request(
+            `http://${address}:${port}/metrics`
+          );
+          statusCodes.metrics = metricsResponse.statusCode;
+          metrics = await metricsResponse.body.text();
+        } finally {
+          await server.close();
+        }
+      }
+    );
+
+    await this.env.run(TASK_NODE);
+
+    assert.deepEqual(statusCodes, {
+      allowed: 200,
+      otherOrigin: 403,
+      otherHttpMethod: 405,
+      tooLarge: 413,
+      notAllowedMethod: 200,
+      disabledMethod: 200,
+      faultedMethod: 200,
+      metrics: 200,
+    });
+    assert.equal(JSON.parse(bodies.notAllowedMethod).error.code, -32601);
+    assert.equal(JSON.parse(bodies.disabledMethod).error.code, -32601);
+    assert.equal(JSON.parse(bodies.faultedMethod).error.code, -32603);
+    assert.include(
+      metrics,
+      'hardhat_jsonrpc_requests_total{method="eth_chainId"} 1'
+    );
+    assert.include(metrics, "hardhat_block_number 0");
+    assert.include(metrics, "hardhat_pending_transactions 0");
+  });
+});
@@ -0,0 +1,17 @@
+module.exports = {
+  networks: {
+    hardhat: {
+      server: {
+        allowedOrigins: ["http://localhost:3000"],
+        allowedHttpMethods: ["post", "get"],
+        maxRequestBodySize: 1000,
+        enableMetrics: true,
+        allowedMethods: ["eth_*", "evm_*"],
+        disabledMethods: ["evm_mine"],
+        faultInjection: {
+          methods: { eth_blockNumber: { errorRate: 1 } },
+        },
+      },
+    },
+  },
+};
@@ -448,6 +448,54 @@ describe("Config resolution", () => {
             httpHeaders: {},
           });
         });
+
+        it("Should let you enable proxyUnsupportedMethods", function () {
+          const config = resolveConfig(__filename, {
+            networks: {
+              hardhat: {
+                forking: {
+                  url: "asd",
+                  proxyUnsupportedMethods: true,
+                },
+              },
+            },
+          });
+
+          assert.deepEqual(config.networks.hardhat.forking, {
+            url: "asd",
+            enabled: true,
+            httpHeaders: {},
+            proxyUnsupportedMethods: true,
+          });
+        });
+      });
+
+      describe("Server settings", function () {
+        it("Should not set a server config by default", function () {
+          const config = resolveConfig(__filename, {});
+
+          assert.isUndefined(config.networks.hardhat.server);
+        });
+
+        it("Should upper-case the allowed HTTP methods", function () {
+          const config = resolveConfig(__filename, {
+            networks: {
+              hardhat: {
+                server: {
+                  allowedOrigins: ["http://localhost:3000"],
+                  allowedHttpMethods: ["post", "Get"],
+                  requestTimeout: 1000,
+                },
+              },
+            },
+          });
+
+          assert.deepEqual(config.networks.hardhat.server, {
+            allowedOrigins: ["http://localhost:3000"],
+            allowedHttpMethods: ["POST", "GET"],
+            requestTimeout: 1000,
+          });
+        });
       });
 
       describe("Accounts settings", function () {
@@ -880,6 +880,18 @@ describe("Config validation", function () {
               }),
             ERRORS.GENERAL.INVALID_CONFIG
           );
+
+          expectHardhatError(
+            () =>
+              validateConfig({
+                networks: {
+                  [HARDHAT_NETWORK_NAME]: {
+                    forking: { url: "asd", proxyUnsupportedMethods: "yes" },
+                  },
+                },
+              }),
+            ERRORS.GENERAL.INVALID_CONFIG
+          );
         });
 
         describe("HardhatNetworkHDAccounstConfig", function () {
@@ -1160,6 +1172,164 @@ describe("Config validation", function () {
             );
           });
         });
+
+        describe("Hardhat network's server config", function () {
+          it("Should accept a valid server config", function () {
+            assert.isEmpty(
+              getValidationErrors({
+                networks: {
+                  [HARDHAT_NETWORK_NAME]: {
+                    server: {
+                      allowedOrigins: ["http://localhost:3000"],
+                      allowedHttpMethods: ["POST"],
+                      maxRequestBodySize: 1024,
+                      requestTimeout: 1000,
+                      allowedMethods: ["eth_*"],
+                      disabledMethods: ["eth_sendTransaction"],
+                      enableMetrics: true,
+                      faultInjection: {
+                        seed: "abc",
+                        latency: 100,
+                        errorRate: 0.1,
+                        methods: { eth_call: { malformedResponseRate: 1 } },
+                      },
+                    },
+                  },
+                },
+              })
+            );
+          });
+
+          it("Should fail with invalid types", function () {
+            expectHardhatError(
+              () =>
+                validateConfig({
+                  networks: {
+                    [HARDHAT_NETWORK_NAME]: {
+                      server: { allowedOrigins: "http://localhost:3000" },
+                    },
+                  },
+                }),
+              ERRORS.GENERAL.INVALID_CONFIG
+            );
+
+            expectHardhatError(
+              () =>
+                validateConfig({
+                  networks: {
+                    [HARDHAT_NETWORK_NAME]: {
+                      server: { requestTimeout: "1000" },
+                    },
+                  },
+                }),
+              ERRORS.GENERAL.INVALID_CONFIG
+            );
+
+            expectHardhatError(
+              () =>
+                validateConfig({
+                  networks: {
+                    [HARDHAT_NETWORK_NAME]: {
+                      server: { enableMetrics: "yes" },
+                    },
+                  },
+                }),
+              ERRORS.GENERAL.INVALID_CONFIG
+            );
+
+            expectHardhatError(
+              () =>
+                validateConfig({
+                  networks: {
+                    [HARDHAT_NETWORK_NAME]: {
+                      server: { disabledMethods: "eth_sendTransaction" },
+                    },
+                  },
+                }),
+              ERRORS.GENERAL.INVALID_CONFIG
+            );
+          });
+
+          it("Should fail if the fault injection settings are out of range", function () {
+            expectHardhatError(
+              () =>
+                validateConfig({
+                  networks: {
+                    [HARDHAT_NETWORK_NAME]: {
+                      server: { faultInjection: { latency: -1 } },
+                    },
+                  },
+                }),
+              ERRORS.GENERAL.INVALID_CONFIG
+            );
+
+            expectHardhatError(
+              () =>
+                validateConfig({
+                  networks: {
+                    [HARDHAT_NETWORK_NAME]: {
+                      server: { faultInjection: { errorRate: 1.5 } },
+                    },
+                  },
+                }),
+              ERRORS.GENERAL.INVALID_CONFIG
+            );
+
+            expectHardhatError(
+              () =>
+                validateConfig({
+                  networks: {
+                    [HARDHAT_NETWORK_NAME]: {
+                      server: {
+                        faultInjection: {
+                          methods: { eth_call: { malformedResponseRate: -1 } },
+                        },
+                      },
+                    },
+                  },
+                }),
+              ERRORS.GENERAL.INVALID_CONFIG
+            );
+          });
+
+          it("Should fail if the sizes or timeouts aren't positive, or the timeout isn't an integer", function () {
+            expectHardhatError(
+              () =>
+                validateConfig({
+                  networks: {
+                    [HARDHAT_NETWORK_NAME]: {
+                      server: { maxRequestBodySize: 0 },
+                    },
+                  },
+                }),
+              ERRORS.GENERAL.INVALID_CONFIG
+            );
+
+            expectHardhatError(
+              () =>
+                validateConfig({
+                  networks: {
+                    [HARDHAT_NETWORK_NAME]: {
+                      server: { requestTimeout: -1 },
+                    },
+                  },
+                }),
+              ERRORS.GENERAL.INVALID_CONFIG
+            );
+
+            expectHardhatError(
+              () =>
+                validateConfig({
+                  networks: {
+                    [HARDHAT_NETWORK_NAME]: {
+                      server: { requestTimeout: 1500.5 },
+                    },
+                  },
+                }),
+              ERRORS.GENERAL.INVALID_CONFIG
+            );
+          });
+        });
       });
 
       describe("HTTP network config", function () {
@@ -0,0 +1,480 @@
+import { assert } from "chai";
+import { EventEmitter } from "events";
+import net from "net";
+import { request } from "undici";
+import WebSocket from "ws";
+
+import { ERRORS } from "../../../../src/internal/core/errors-list";
+import {
+  InvalidInputError,
+  MethodNotFoundError,
+} from "../../../../src/internal/core/providers/errors";
+import {
+  JsonRpcServer,
+  JsonRpcServerConfig,
+} from "../../../../src/internal/hardhat-network/jsonrpc/server";
+import { EIP1193Provider, RequestArguments } from "../../../../src/types";
+import { expectHardhatError } from "../../../helpers/errors";
+
+class FakeProvider extends EventEmitter implements EIP1193Provider {
+  public async request({ method }: RequestArguments): Promise<unknown> {
+    if (method === "test_fail") {
+      throw new InvalidInputError("failed");
+    }
+
+    if (method === "eth_blockNumber") {
+      return "0x5";
+    }
+
+    if (method === "eth_getBlockTransactionCountByNumber") {
+      return "0x2";
+    }
+
+    if (method.startsWith("unknown_")) {
+      throw new MethodNotFoundError(`Method ${method} not found`);
+    }
+
+    return method;
+  }
+}
+
+describe("JsonRpcServer", function () {
+  let server: JsonRpcServer;
+  let url: string;
+
+  async function startServer(
+    config: Omit<JsonRpcServerConfig, "hostname" | "port" | "provider">
+  ) {
+    server = new JsonRpcServer({
+      hostname: "127.0.0.1",
+      port: 0,
+      provider: new FakeProvider(),
+      ...config,
+    });
+
+    const { address, port } = await server.listen();
+    url = `http://${address}:${port}/`;
+  }
+
+  function sendRequest(
+    method: string,
+    options: {
+      httpMethod?: "GET" | "POST" | "PUT";
+      headers?: Record<string, string>;
+      params?: unknown[];
+    } = {}
+  ) {
+    return request(url, {
+      method: options.httpMethod ?? "POST",
+      headers: { "Content-Type": "application/json", ...options.headers },
+      body: JSON.stringify({
+        jsonrpc: "2.0",
+        id: 1,
+        method,
+        params: options.params ?? [],
+      }),
+    });
+  }
+
+  function connectWs(options: WebSocket.ClientOptions = {}) {
+    return new WebSocket(url.replace("http", "ws"), options);
+  }
+
+  afterEach(async function () {
+    await server.close();
+  });
+
+  describe("default config", function () {
+    beforeEach(async function () {
+      await startServer({});
+    });
+
+    it("should allow any origin", async function () {
+      const response = await sendRequest("eth_chainId", {
+        headers: { Origin: "http://example.com" },
+      });
+
+      assert.equal(response.statusCode, 200);
+      assert.equal(response.headers["access-control-allow-origin"], "*");
+      assert.equal((await response.body.json()).result, "eth_chainId");
+    });
+  });
+
+  describe("allowedOrigins", function () {
+    beforeEach(async function () {
+      await startServer({ allowedOrigins: ["http://localhost:3000"] });
+    });
+
+    it("should echo an allowed origin", async function () {
+      const response = await sendRequest("eth_chainId", {
+        headers: { Origin: "http://localhost:3000" },
+      });
+
+      assert.equal(response.statusCode, 200);
+      assert.equal(
+        response.headers["access-control-allow-origin"],
+        "http://localhost:3000"
+      );
+      await response.body.dump();
+    });
+
+    it("should reject other origins", async function () {
+      const response = await sendRequest("eth_chainId", {
+        headers: { Origin: "http://example.com" },
+      });
+
+      assert.equal(response.statusCode, 403);
+      await response.body.dump();
+    });
+
+    it("should accept requests without an origin", async function () {
+      const response = await sendRequest("eth_chainId");
+
+      assert.equal(response.statusCode, 200);
+      await response.body.dump();
+    });
+
+    it("should accept websocket connections from an allowed origin", async function () {
+      const ws = connectWs({ origin: "http://localhost:3000" });
+      await new Promise((resolve) => ws.on("open", resolve));
+
+      ws.send(JSON.stringify({ jsonrpc: "2.0", id: 1, method: "eth_chainId" }));
+      const message = await new Promise<WebSocket.Data>((resolve) =>
+        ws.on("message", resolve)
+      );
+
+      assert.equal(JSON.parse(message.toString()).result, "eth_chainId");
+      ws.terminate();
+    });
+
+    it("should reject websocket connections from other origins", async function () {
+      const ws = connectWs({ origin: "http://example.com" });
+      const error = await new Promise<Error>((resolve) =>
+        ws.on("error", resolve)
+      );
+
+      assert.include(error.message, "401");
+    });
+  });
+
+  describe("allowedHttpMethods", function () {
+    beforeEach(async function () {
+      await startServer({ allowedHttpMethods: ["POST"] });
+    });
+
+    it("should accept allowed methods", async function () {
+      const response = await sendRequest("eth_chainId");
+
+      assert.equal(response.statusCode, 200);
+      assert.equal(
+        response.headers["access-control-allow-methods"],
+        "OPTIONS, POST"
+      );
+      await response.body.dump();
+    });
+
+    it("should reject other methods", async function () {
+      const response = await sendRequest("eth_chainId", { httpMethod: "PUT" });
+
+      assert.equal(response.statusCode, 405);
+      await response.body.dump();
+    });
+  });
+
+  describe("allowedHttpMethods in lower case", function () {
+    beforeEach(async function () {
+      await startServer({ allowedHttpMethods: ["post"] });
+    });
+
+    it("should compare methods case-insensitively", async function () {
+      const response = await sendRequest("eth_chainId");
+
+      assert.equal(response.statusCode, 200);
+      await response.body.dump();
+    });
+  });
+
+  describe("maxRequestBodySize", function () {
+    beforeEach(async function () {
+      await startServer({ maxRequestBodySize: 100 });
+    });
+
+    it("should accept small requests", async function () {
+      const response = await sendRequest("eth_chainId");
+
+      assert.equal(response.statusCode, 200);
+      await response.body.dump();
+    });
+
+    it("should reject requests that are too large", async function () {
+      const response = await sendRequest("eth_call", {
+        params: ["0x".padEnd(200, "0")],
+      });
+
+      assert.equal(response.statusCode, 413);
+      const body = await response.body.json();
+      assert.equal(body.error.code, -32600);
+    });
+
+    it("should close websocket connections that send messages that are too large", async function () {
+      const ws = connectWs();
+      await new Promise((resolve) => ws.on("open", resolve));
+
+      ws.send(
+        JSON.stringify({
+          jsonrpc: "2.0",
+          id: 1,
+          method: "eth_call",
+          params: ["0x".padEnd(200, "0")],
+        })
+      );
+      const code = await new Promise<number>((resolve) =>
+        ws.on("close", resolve)
+      );
+
+      // 1009 is the "message too big" close code
+      assert.equal(code, 1009);
+    });
+  });
+
+  describe("requestTimeout", function () {
+    beforeEach(async function () {
+      await startServer({ requestTimeout: 200 });
+    });
+
+    it("should accept requests received in time", async function () {
+      const response = await sendRequest("eth_chainId");
+
+      assert.equal(response.statusCode, 200);
+      await response.body.dump();
+    });
+
+    it("should reject requests that take too long to be received", async function () {
+      const { hostname, port } = new URL(url);
+      const socket = net.connect(Number(port), hostname);
+
+      // send the headers and only part of the body
+      socket.write(
+        "POST / HTTP/1.1\r\nHost: localhost\r\nContent-Type: application/json\r\nContent-Length: 100\r\n\r\n{"
+      );
+
+      const response = await new Promise<string>((resolve) => {
+        let data = "";
+        socket.on("data", (chunk) => (data += chunk.toString()));
+        socket.on("close", () => resolve(data));
+      });
+
+      assert.match(response, /^HTTP\/1\.1 408/);
+    });
+  });
+
+  describe("allowedMethods and disabledMethods", function () {
+    beforeEach(async function () {
+      await startServer({
+        allowedMethods: ["eth_*", "net_version"],
+        disabledMethods: ["eth_sendTransaction"],
+      });
+    });
+
+    it("should accept allowed methods", async function () {
+      for (const method of ["eth_chainId", "net_version"]) {
+        const response = await sendRequest(method);
+        assert.equal((await response.body.json()).result, method);
+      }
+    });
+
+    it("should reject methods that aren't allowed", async function () {
+      const response = await sendRequest("evm_mine");
+      const body = await response.body.json();
+
+      assert.equal(body.error.code, -32601);
+      assert.equal(body.id, 1);
+    });
+
+    it("should reject disabled methods even if they match an allowed pattern", async function () {
+      const response = await sendRequest("eth_sendTransaction");
+      const body = await response.body.json();
+
+      assert.equal(body.error.code, -32601);
+    });
+  });
+
+  describe("faultInjection", function () {
+    it("should fail every request with an error rate of 1", async function () {
+      await startServer({ faultInjection: { errorRate: 1 } });
+
+      const response = await sendRequest("eth_chainId");
+      const body = await response.body.json();
+
+      assert.equal(body.error.code, -32603);
+      assert.equal(body.id, 1);
+    });
+
+    it("should return malformed responses", async function () {
+      await startServer({ faultInjection: { malformedResponseRate: 1 } });
+
+      const response = await sendRequest("eth_chainId");
+      const body = await response.body.json();
+
+      assert.deepEqual(body, { jsonrpc: "2.0", id: 1 });
+    });
+
+    it("should let methods override the global settings", async function () {
+      await startServer({
+        faultInjection: {
+          errorRate: 1,
+          methods: { net_version: { errorRate: 0 } },
+        },
+      });
+
+      const response = await sendRequest("net_version");
+      assert.equal((await response.body.json()).result, "net_version");
+    });
+
+    it("should add latency", async function () {
+      await startServer({ faultInjection: { latency: 200 } });
+
+      const start = Date.now();
+      await (await sendRequest("eth_chainId")).body.dump();
+
+      assert.isAtLeast(Date.now() - start, 200);
+    });
+
+    it("should not inject faults into subscription methods", async function () {
+      await startServer({
+        faultInjection: { errorRate: 1, malformedResponseRate: 1 },
+      });
+
+      const ws = connectWs();
+      await new Promise((resolve) => ws.on("open", resolve));
+
+      ws.send(
+        JSON.stringify({
+          jsonrpc: "2.0",
+          id: 1,
+          method: "eth_subscribe",
+          params: ["newHeads"],
+        })
+      );
+      const message = await new Promise<WebSocket.Data>((resolve) =>
+        ws.on("message", resolve)
+      );
+
+      assert.equal(JSON.parse(message.toString()).result, "eth_subscribe");
+      ws.terminate();
+    });
+
+    it("should reject invalid settings", async function () {
+      const invalidConfigs = [
+        { latency: -1 },
+        { errorRate: 1.5 },
+        { malformedResponseRate: -0.1 },
+        { methods: { eth_chainId: { errorRate: 2 } } },
+      ];
+
+      for (const faultInjection of invalidConfigs) {
+        expectHardhatError(
+          () =>
+            new JsonRpcServer({
+              hostname: "127.0.0.1",
+              port: 0,
+              provider: new FakeProvider(),
+              faultInjection,
+            }),
+          ERRORS.GENERAL.INVALID_CONFIG
+        );
+      }
+
+      // start a server so that afterEach can close it
+      await startServer({});
+    });
+
+    it("should inject the same faults given the same seed", async function () {
+      const getErrorPattern = async () => {
+        await startServer({ faultInjection: { seed: "abc", errorRate: 0.5 } });
+
+        const pattern = [];
+        for (let i = 0; i < 10; i++) {
+          const body = await (await sendRequest("eth_chainId")).body.json();
+          pattern.push(body.error !== undefined);
+        }
+
+        await server.close();
+        return pattern;
+      };
+
+      const firstPattern = await getErrorPattern();
+      const secondPattern = await getErrorPattern();
+
+      assert.deepEqual(firstPattern, secondPattern);
+      assert.include(firstPattern, true);
+      assert.include(firstPattern, false);
+      // start a server so that afterEach can close it
+      await startServer({});
+    });
+  });
+
+  describe("enableMetrics", function () {
+    it("should serve request metrics", async function () {
+      await startServer({ enableMetrics: true });
+
+      await (await sendRequest("eth_chainId")).body.dump();
+      await (await sendRequest("eth_chainId")).body.dump();
+      await (await sendRequest("test_fail")).body.dump();
+
+      const response = await request(`${url}metrics`);
+      assert.equal(response.statusCode, 200);
+
+      const metrics = await response.body.text();
+      assert.include(
+        metrics,
+        'hardhat_jsonrpc_requests_total{method="eth_chainId"} 2'
+      );
+      assert.include(
+        metrics,
+        'hardhat_jsonrpc_request_errors_total{method="eth_chainId"} 0'
+      );
+      assert.include(
+        metrics,
+        'hardhat_jsonrpc_request_errors_total{method="test_fail"} 1'
+      );
+      assert.include(
+        metrics,
+        'hardhat_jsonrpc_request_duration_seconds_count{method="eth_chainId"} 2'
+      );
+      assert.include(metrics, "hardhat_block_number 5");
+      assert.include(metrics, "hardhat_pending_transactions 2");
+    });
+
+    it("should use a single label for unknown methods", async function () {
+      await startServer({ enableMetrics: true });
+
+      await (await sendRequest("unknown_a")).body.dump();
+      await (await sendRequest("unknown_b")).body.dump();
+
+      const metrics = await (await request(`${url}metrics`)).body.text();
+      assert.include(
+        metrics,
+        'hardhat_jsonrpc_requests_total{method="unknown"} 2'
+      );
+      assert.notInclude(metrics, "unknown_a");
+      assert.notInclude(metrics, "unknown_b");
+    });
+
+    it("should not serve metrics if GET requests aren't allowed", async function () {
+      await startServer({ enableMetrics: true, allowedHttpMethods: ["POST"] });
+
+      const response = await request(`${url}metrics`);
+      assert.equal(response.statusCode, 405);
+      await response.body.dump();
+    });
+
+    it("should not serve metrics by default", async function () {
+      await startServer({});
+
+      const response = await request(`${url}metrics`);
+      const body = await response.body.json();
+      assert.equal(body.error.code, -32700);
+    });
+  });
+});
@@ -0,0 +1,197 @@
+import { assert } from "chai";
+import { EventEmitter } from "events";
+
+import { defaultHardhatNetworkParams } from "../../../../src/internal/core/config/default-config";
+import {
+  InvalidInputError,
+  MethodNotFoundError,
+  MethodNotSupportedError,
+} from "../../../../src/internal/core/providers/errors";
+import { JsonRpcServer } from "../../../../src/internal/hardhat-network/jsonrpc/server";
+import { ForkConfig } from "../../../../src/internal/hardhat-network/provider/node-types";
+import { createHardhatNetworkProvider } from "../../../../src/internal/hardhat-network/provider/provider";
+import { EIP1193Provider, RequestArguments } from "../../../../src/types";
+
+import {
+  DEFAULT_ACCOUNTS,
+  DEFAULT_BLOCK_GAS_LIMIT,
+  DEFAULT_CHAIN_ID,
+  DEFAULT_HARDFORK,
+  DEFAULT_NETWORK_ID,
+} from "../helpers/providers";
+
+const CUSTOM_METHOD = "test_customMethod";
+
+/**
+ * A provider that answers a method that Hardhat Network doesn't support, and
+ * forwards everything else to a Hardhat Network provider.
+ */
+class RemoteProvider extends EventEmitter implements EIP1193Provider {
+  constructor(private readonly _provider: EIP1193Provider) {
+    super();
+  }
+
+  public async request(args: RequestArguments): Promise<unknown> {
+    if (args.method === CUSTOM_METHOD) {
+      return { handledBy: "remote", params: args.params };
+    }
+
+    return this._provider.request(args);
+  }
+}
+
+function createProvider(
+  forkConfig?: ForkConfig,
+  proxyUnsupportedMethods?: boolean
+): Promise<EIP1193Provider> {
+  return createHardhatNetworkProvider(
+    {
+      hardfork: DEFAULT_HARDFORK,
+      chainId: DEFAULT_CHAIN_ID,
+      networkId: DEFAULT_NETWORK_ID,
+      blockGasLimit: Number(DEFAULT_BLOCK_GAS_LIMIT),
+      minGasPrice: 0n,
+      throwOnTransactionFailures: true,
+      throwOnCallFailures: true,
+      automine: true,
+      intervalMining: 0,
+      mempoolOrder: "priority",
+      chains: defaultHardhatNetworkParams.chains,
+      genesisAccounts: DEFAULT_ACCOUNTS,
+      allowUnlimitedContractSize: false,
+      allowBlocksWithSameTimestamp: false,
+      forkConfig,
+      proxyUnsupportedMethods,
+      enableTransientStorage: false,
+      enableRip7212: false,
+    },
+    {
+      enabled: false,
+      printLineFn: () => {},
+      replaceLastLineFn: () => {},
+    }
+  );
+}
+
+async function getErrorCode(promise: Promise<unknown>): Promise<number> {
+  try {
+    await promise;
+  } catch (error: any) {
+    return error.code;
+  }
+
+  assert.fail("Expected the request to fail");
+}
+
+function assertIsUnsupportedMethodError(code: number) {
+  assert.oneOf(code, [MethodNotFoundError.CODE, MethodNotSupportedError.CODE]);
+}
+
+describe("proxyUnsupportedMethods", function () {
+  let remoteServer: JsonRpcServer;
+  let remoteUrl: string;
+
+  before(async function () {
+    remoteServer = new JsonRpcServer({
+      hostname: "127.0.0.1",
+      port: 0,
+      provider: new RemoteProvider(await createProvider()),
+    });
+
+    const { address, port } = await remoteServer.listen();
+    remoteUrl = `http://${address}:${port}/`;
+  });
+
+  after(async function () {
+    await remoteServer.close();
+  });
+
+  it("should forward unsupported methods to the forked node", async function () {
+    const provider = await createProvider({ jsonRpcUrl: remoteUrl }, true);
+
+    const result = await provider.request({
+      method: CUSTOM_METHOD,
+      params: [1, "a"],
+    });
+
+    assert.deepEqual(result, { handledBy: "remote", params: [1, "a"] });
+  });
+
+  it("should forward unsupported methods with params sent as an object", async function () {
+    const provider = await createProvider({ jsonRpcUrl: remoteUrl }, true);
+
+    const result = await provider.request({
+      method: CUSTOM_METHOD,
+      params: { a: 1 },
+    });
+
+    assert.deepEqual(result, { handledBy: "remote", params: { a: 1 } });
+  });
+
+  it("should reject params sent as an object if nothing is forwarded", async function () {
+    const provider = await createProvider({ jsonRpcUrl: remoteUrl }, false);
+
+    assert.equal(
+      await getErrorCode(
+        provider.request({ method: CUSTOM_METHOD, params: { a: 1 } })
+      ),
+      InvalidInputError.CODE
+    );
+  });
+
+  it("should not forward methods that Hardhat Network supports", async function () {
+    const provider = await createProvider({ jsonRpcUrl: remoteUrl }, true);
+
+    await provider.request({ method: "evm_mine" });
+
+    // the local chain advanced, and the remote one didn't
+    const localBlockNumber = await provider.request({
+      method: "eth_blockNumber",
+    });
+    const remoteBlockNumber = await remoteServer
+      .getProvider()
+      .request({ method: "eth_blockNumber" });
+    assert.notEqual(localBlockNumber, remoteBlockNumber);
+  });
+
+  it("should not forward anything if the option is disabled", async function () {
+    const provider = await createProvider({ jsonRpcUrl: remoteUrl }, false);
+
+    assertIsUnsupportedMethodError(
+      await getErrorCode(provider.request({ method: CUSTOM_METHOD }))
+    );
+  });
+
+  it("should not forward anything if forking is disabled", async function () {
+    const provider = await createProvider(undefined, true);
+
+    assertIsUnsupportedMethodError(
+      await getErrorCode(provider.request({ method: CUSTOM_METHOD }))
+    );
+  });
+
+  it("should stop forwarding after resetting to a non-forked network", async function () {
+    const provider = await createProvider({ jsonRpcUrl: remoteUrl }, true);
+
+    await provider.request({ method: "hardhat_reset", params: [] });
+
+    assertIsUnsupportedMethodError(
+      await getErrorCode(provider.request({ method: CUSTOM_METHOD }))
+    );
+  });
+
+  it("should start forwarding after resetting to a forked network", async function () {
+    const provider = await createProvider(undefined, true);
+
+    await provider.request({
+      method: "hardhat_reset",
+      params: [{ forking: { jsonRpcUrl: remoteUrl } }],
+    });
+
+    const result = await provider.request({
+      method: CUSTOM_METHOD,
+      params: ["b"],
+    });
+    assert.deepEqual(result, { handledBy: "remote", params: ["b"] });
+  });
+});
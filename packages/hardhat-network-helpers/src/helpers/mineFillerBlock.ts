@@ -0,0 +1,122 @@
+import type { NumberLike } from "../types";
+import { HardhatNetworkHelpersError } from "../errors";
+import { getHardhatProvider, toBigInt, toRpcQuantity } from "../utils";
+
+const FILLER_SENDER = "0x000000000000000000000000000000000000f111";
+// the code of this account is the INVALID opcode, which consumes all the gas
+// sent to it
+const FILLER_CONTRACT = "0x000000000000000000000000000000000000f112";
+const INVALID_OPCODE = "0xfe";
+const TX_BASE_GAS = BigInt(21000);
+
+/**
+ * Mines a block that includes a filler transaction consuming exactly the
+ * given amount of gas. Pending transactions are included in the same block
+ * if they fit.
+ *
+ * The filler transaction always fails, and it's sent from an account whose
+ * nonce is increased and that isn't impersonated afterwards. The code and
+ * balance that the helper sets on its accounts are restored afterwards.
+ *
+ * @param gasUsed The amount of gas the filler transaction should consume.
+ */
+export async function mineFillerBlock(gasUsed: NumberLike): Promise<void> {
+  const provider = await getHardhatProvider();
+
+  const gas = toBigInt(gasUsed);
+  if (gas < TX_BASE_GAS) {
+    throw new HardhatNetworkHelpersError(
+      `Invalid gas ${gas}: a transaction consumes at least ${TX_BASE_GAS} gas`
+    );
+  }
+
+  const pendingBlock = (await provider.request({
+    method: "eth_getBlockByNumber",
+    params: ["pending", false],
+  })) as { gasLimit: string; gasUsed: string };
+  const blockGasLimit = BigInt(pendingBlock.gasLimit);
+  if (gas > blockGasLimit) {
+    throw new HardhatNetworkHelpersError(
+      `Invalid gas ${gas}: it exceeds the block gas limit ${blockGasLimit}`
+    );
+  }
+
+  const pendingGasUsed = BigInt(pendingBlock.gasUsed);
+  if (pendingGasUsed + gas > blockGasLimit) {
+    throw new HardhatNetworkHelpersError(
+      `Invalid gas ${gas}: the pending transactions already use ${pendingGasUsed} of the block gas limit ${blockGasLimit}`
+    );
+  }
+
+  const automine = (await provider.request({
+    method: "hardhat_getAutomine",
+  })) as boolean;
+  const previousCode = (await provider.request({
+    method: "eth_getCode",
+    params: [FILLER_CONTRACT, "latest"],
+  })) as string;
+  const previousBalance = (await provider.request({
+    method: "eth_getBalance",
+    params: [FILLER_SENDER, "latest"],
+  })) as string;
+
+  try {
+    await provider.request({
+      method: "hardhat_setCode",
+      params: [FILLER_CONTRACT, INVALID_OPCODE],
+    });
+    await provider.request({
+      method: "hardhat_setBalance",
+      params: [FILLER_SENDER, toRpcQuantity(BigInt(2) ** BigInt(128))],
+    });
+    await provider.request({
+      method: "hardhat_impersonateAccount",
+      params: [FILLER_SENDER],
+    });
+
+    // with automine disabled, the failed filler transaction doesn't throw
+    await provider.request({ method: "evm_setAutomine", params: [false] });
+
+    const txHash = (await provider.request({
+      method: "eth_sendTransaction",
+      params: [
+        {
+          from: FILLER_SENDER,
+          to: FILLER_CONTRACT,
+          gas: toRpcQuantity(gas),
+        },
+      ],
+    })) as string;
+
+    await provider.request({ method: "evm_mine" });
+
+    const receipt = await provider.request({
+      method: "eth_getTransactionReceipt",
+      params: [txHash],
+    });
+    if (receipt === null) {
+      await provider.request({
+        method: "hardhat_dropTransaction",
+        params: [txHash],
+      });
+
+      throw new HardhatNetworkHelpersError(
+        `The filler transaction didn't fit in the block next to the pending transactions. A block was mined without it.`
+      );
+    }
+  } finally {
+    await provider.request({
+      method: "hardhat_stopImpersonatingAccount",
+      params: [FILLER_SENDER],
+    });
+    await provider.request({
+      method: "hardhat_setBalance",
+      params: [FILLER_SENDER, previousBalance],
+    });
+    await provider.request({
+      method: "hardhat_setCode",
+      params: [FILLER_CONTRACT, previousCode],
+    });
+    await provider.request({ method: "evm_setAutomine", params: [automine] });
+  }
+}
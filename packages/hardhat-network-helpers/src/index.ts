@@ -3,6 +3,7 @@ export * as time from "./helpers/time";
 
 export { mine } from "./helpers/mine";
 export { mineUpTo } from "./helpers/mineUpTo";
+export { mineFillerBlock } from "./helpers/mineFillerBlock";
 export { dropTransaction } from "./helpers/dropTransaction";
 export { getStorageAt } from "./helpers/getStorageAt";
 export { impersonateAccount } from "./helpers/impersonateAccount";
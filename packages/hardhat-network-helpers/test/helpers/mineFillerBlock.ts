@@ -0,0 +1,110 @@
+import { assert } from "chai";
+
+import * as hh from "../../src";
+import { useEnvironment, rpcQuantityToNumber } from "../test-utils";
+
+describe("mineFillerBlock", function () {
+  useEnvironment("simple");
+
+  const getLatestBlock = async () => {
+    return this.ctx.hre.network.provider.send("eth_getBlockByNumber", [
+      "latest",
+      false,
+    ]);
+  };
+
+  it("should mine a block that uses exactly the given gas", async function () {
+    const blockNumberBefore = await hh.time.latestBlock();
+
+    await hh.mineFillerBlock(1_000_000);
+
+    const block = await getLatestBlock();
+    assert.equal(rpcQuantityToNumber(block.number), blockNumberBefore + 1);
+    assert.equal(rpcQuantityToNumber(block.gasUsed), 1_000_000);
+    assert.lengthOf(block.transactions, 1);
+  });
+
+  it("should include pending transactions in the same block", async function () {
+    await this.hre.network.provider.send("evm_setAutomine", [false]);
+    const [sender] = await this.hre.network.provider.send("eth_accounts");
+    await this.hre.network.provider.send("eth_sendTransaction", [
+      { from: sender, to: sender },
+    ]);
+
+    await hh.mineFillerBlock(100_000);
+
+    const block = await getLatestBlock();
+    assert.equal(rpcQuantityToNumber(block.gasUsed), 121_000);
+    assert.lengthOf(block.transactions, 2);
+    assert.isFalse(
+      await this.hre.network.provider.send("hardhat_getAutomine"),
+      "automine should be restored"
+    );
+  });
+
+  it("should throw without mining if the gas doesn't fit next to the pending transactions", async function () {
+    await this.hre.network.provider.send("evm_setAutomine", [false]);
+    const [sender] = await this.hre.network.provider.send("eth_accounts");
+    await this.hre.network.provider.send("eth_sendTransaction", [
+      { from: sender, to: sender },
+    ]);
+    const block = await getLatestBlock();
+    const blockNumberBefore = await hh.time.latestBlock();
+
+    await assert.isRejected(
+      hh.mineFillerBlock(BigInt(block.gasLimit) - BigInt(20_000))
+    );
+
+    assert.equal(await hh.time.latestBlock(), blockNumberBefore);
+  });
+
+  it("should restore the code and balance of the filler accounts", async function () {
+    await hh.setBalance("0x000000000000000000000000000000000000f111", 123);
+
+    await hh.mineFillerBlock(100_000);
+
+    assert.equal(
+      await this.hre.network.provider.send("eth_getBalance", [
+        "0x000000000000000000000000000000000000f111",
+        "latest",
+      ]),
+      "0x7b"
+    );
+    assert.equal(
+      await this.hre.network.provider.send("eth_getCode", [
+        "0x000000000000000000000000000000000000f112",
+        "latest",
+      ]),
+      "0x"
+    );
+  });
+
+  it("should mine a failed filler transaction", async function () {
+    await hh.mineFillerBlock(100_000);
+
+    const block = await getLatestBlock();
+    const receipt = await this.hre.network.provider.send(
+      "eth_getTransactionReceipt",
+      [block.transactions[0]]
+    );
+    assert.equal(receipt.status, "0x0");
+  });
+
+  it("should restore automine", async function () {
+    await hh.mineFillerBlock(21_000);
+
+    assert.isTrue(await this.hre.network.provider.send("hardhat_getAutomine"));
+  });
+
+  it("should throw if the gas is lower than the intrinsic gas", async function () {
+    await assert.isRejected(hh.mineFillerBlock(20_999));
+  });
+
+  it("should throw if the gas exceeds the block gas limit", async function () {
+    const block = await getLatestBlock();
+
+    await assert.isRejected(
+      hh.mineFillerBlock(BigInt(block.gasLimit) + BigInt(1))
+    );
+  });
+});